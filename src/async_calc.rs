@@ -0,0 +1,175 @@
+//! Derived values fed by a future instead of a synchronous closure.
+//!
+//! A [`Calc`] recomputes synchronously and in-line with whatever signal triggered it. An
+//! [`AsyncCalc`] instead kicks off a background task on bevy's task pool and, once it resolves,
+//! feeds the result back into the graph through the usual [`crate::calculation::apply`] path so
+//! its own subscribers recompute exactly as if it were any other observable.
+
+use std::future::Future;
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, Task};
+
+use crate::{
+    calculation::{self, CalcFunction},
+    observable::Observable,
+    ReactiveContext,
+};
+
+/// The value held by an [`AsyncCalc`]: either its future hasn't resolved yet, or it has and this
+/// is the most recent result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsyncState<T> {
+    Loading,
+    Ready(T),
+}
+
+/// Bumped every time an [`AsyncCalc`] (re)dispatches its future, so a task that finishes after a
+/// newer one was already dispatched (because its dependencies changed again mid-flight) can tell
+/// it's stale and discard its result instead of clobbering the newer one.
+#[derive(Component)]
+struct AsyncGeneration(u64);
+
+/// A derived value computed by a future, rather than a plain closure. See the module docs.
+#[derive(Debug, Component)]
+pub struct AsyncCalc<T: Send + Sync + 'static> {
+    pub(crate) reactor_entity: Entity,
+    p: PhantomData<T>,
+}
+
+impl<T: Send + Sync> Clone for AsyncCalc<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync> Copy for AsyncCalc<T> {}
+
+impl<T: Send + Sync + PartialEq + 'static> Observable for AsyncCalc<T> {
+    type DataType = AsyncState<T>;
+    fn reactive_entity(&self) -> Entity {
+        self.reactor_entity
+    }
+}
+
+impl<T: Send + Sync + PartialEq + 'static> AsyncCalc<T> {
+    /// Build an async calc. `derive_fn` runs synchronously every time one of the observables it
+    /// reads via `cx.read(..)` changes — same dynamic dependency tracking as
+    /// [`crate::calculation::Calc::new_dynamic`] — and returns the future to dispatch. The future
+    /// itself runs on [`AsyncComputeTaskPool`], off the reactive graph entirely, and can't read or
+    /// write it directly; pull whatever it needs out of `cx` before returning the future.
+    pub fn new<Fut>(
+        rctx: &mut ReactiveContext,
+        derive_fn: impl Fn(&mut ReactiveContext) -> Fut + Send + Sync + Clone + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        rctx.world.entity_mut(entity).insert(AsyncGeneration(0));
+
+        let dispatch = move |cx: &mut ReactiveContext| -> AsyncState<T> {
+            let future = derive_fn(cx);
+            let generation = {
+                let mut generation = cx.world.get_mut::<AsyncGeneration>(entity).unwrap();
+                generation.0 += 1;
+                generation.0
+            };
+            let task = AsyncComputeTaskPool::get().spawn(future);
+            cx.pending_async.push(Box::new(PendingTask {
+                entity,
+                generation,
+                task,
+            }));
+            AsyncState::Loading
+        };
+
+        let mut calc_fn = CalcFunction::new_dynamic(dispatch);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+
+    /// Read this calc's current [`AsyncState`]. Same subscription semantics as
+    /// [`crate::calculation::Calc::read`].
+    pub fn read<'r>(&self, rctx: &'r mut ReactiveContext) -> &'r AsyncState<T> {
+        rctx.read(*self)
+    }
+
+    /// `true` while the most recently dispatched future hasn't resolved yet.
+    pub fn pending(&self, rctx: &mut ReactiveContext) -> bool {
+        matches!(self.read(rctx), AsyncState::Loading)
+    }
+
+    /// How many times this calc has (re)dispatched its future. Bumps every time its dependencies
+    /// change, even before the new future resolves, so it can be used to notice "a new load just
+    /// started" independent of [`AsyncCalc::pending`].
+    pub fn version(&self, rctx: &ReactiveContext) -> u64 {
+        rctx.world
+            .get::<AsyncGeneration>(self.reactor_entity)
+            .map_or(0, |generation| generation.0)
+    }
+}
+
+impl ReactiveContext {
+    /// Build an [`AsyncCalc`]. See [`AsyncCalc::new`].
+    pub fn calc_async<T: Send + Sync + PartialEq + 'static, Fut>(
+        &mut self,
+        derive_fn: impl Fn(&mut ReactiveContext) -> Fut + Send + Sync + Clone + 'static,
+    ) -> AsyncCalc<T>
+    where
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        AsyncCalc::new(self, derive_fn)
+    }
+
+    /// Poll every in-flight async task, writing resolved values back into the graph and running
+    /// the reaction graph to completion for any that landed. Stale tasks (superseded by a newer
+    /// dispatch of the same [`AsyncCalc`] before they finished) are dropped silently. Called once
+    /// per frame by [`crate::ReactiveExtensionsPlugin`].
+    pub(crate) fn poll_pending_async(&mut self) {
+        let mut stack = Vec::new();
+        let mut pending = std::mem::take(&mut self.pending_async);
+        pending.retain_mut(|task| !task.poll(self, &mut stack));
+        self.pending_async = pending;
+        calculation::drain_stack(self, &mut stack);
+    }
+}
+
+/// Type-erased handle to an in-flight [`AsyncCalc`] task, so [`ReactiveContext::pending_async`]
+/// can hold a heterogeneous set of tasks without knowing each one's concrete output type.
+pub(crate) trait ErasedPendingTask: Send + Sync {
+    /// Poll the underlying task. If it has resolved, write the value back (unless a newer
+    /// dispatch already made it stale) and return `true` so the caller drops it from the pending
+    /// list.
+    fn poll(&mut self, rctx: &mut ReactiveContext, stack: &mut Vec<Entity>) -> bool;
+}
+
+struct PendingTask<T: Send + Sync + PartialEq + 'static> {
+    entity: Entity,
+    generation: u64,
+    task: Task<T>,
+}
+
+impl<T: Send + Sync + PartialEq + 'static> ErasedPendingTask for PendingTask<T> {
+    fn poll(&mut self, rctx: &mut ReactiveContext, stack: &mut Vec<Entity>) -> bool {
+        let Some(value) =
+            bevy_tasks::block_on(bevy_tasks::futures_lite::future::poll_once(&mut self.task))
+        else {
+            return false;
+        };
+        let current_generation = rctx
+            .world
+            .get::<AsyncGeneration>(self.entity)
+            .map_or(0, |generation| generation.0);
+        if current_generation == self.generation {
+            calculation::apply(&mut rctx.world, self.entity, AsyncState::Ready(value), stack);
+        }
+        true
+    }
+}
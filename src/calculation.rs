@@ -0,0 +1,405 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::all_tuples_with_size;
+
+use crate::{
+    observable::{Observable, ObservableData},
+    ReactiveContext,
+};
+
+/// A reactive component whose value is recalculated automatically, and can only be read through
+/// the [`ReactiveContext`].
+#[derive(Debug, Component)]
+pub struct Calc<T: Send + Sync + 'static> {
+    pub(crate) reactor_entity: Entity,
+    pub(crate) p: PhantomData<T>,
+}
+
+impl<T: Send + Sync + 'static> Observable for Calc<T> {
+    type DataType = T;
+    fn reactive_entity(&self) -> Entity {
+        self.reactor_entity
+    }
+}
+
+impl<T: Send + Sync> Clone for Calc<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync> Copy for Calc<T> {}
+
+impl<T: PartialEq + Send + Sync + 'static> Calc<T> {
+    /// Build a calc whose inputs are a fixed tuple of observables, known up front.
+    pub fn new<D: CalcQuery<T>>(
+        rctx: &mut ReactiveContext,
+        input_deps: D,
+        derive_fn: (impl Fn(D::Query<'_>) -> T + Send + Sync + Clone + 'static),
+    ) -> Self {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        let mut calc_fn = CalcFunction::new(input_deps, derive_fn);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+
+    /// Build a calc whose inputs are discovered dynamically, by recording whatever the closure
+    /// actually reads through [`ReactiveContext::read`] each time it runs. Unlike [`Calc::new`],
+    /// this can change its dependency set from one run to the next (an `if` that only reads one
+    /// branch only subscribes to that branch), and isn't bounded by a fixed tuple arity.
+    pub fn new_dynamic(
+        rctx: &mut ReactiveContext,
+        derive_fn: impl Fn(&mut ReactiveContext) -> T + Send + Sync + Clone + 'static,
+    ) -> Self {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        let mut calc_fn = CalcFunction::new_dynamic(derive_fn);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+
+    /// Build a calc whose derivation also receives its own previous value (`None` on the first
+    /// run), so it can update incrementally instead of recomputing from scratch every time —
+    /// running sums, moving averages, or state machines living inside the reactive graph.
+    pub fn new_folded<D: CalcQuery<T>>(
+        rctx: &mut ReactiveContext,
+        input_deps: D,
+        derive_fn: (impl Fn(Option<&T>, D::Query<'_>) -> T + Send + Sync + Clone + 'static),
+    ) -> Self {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        let mut calc_fn = CalcFunction::new_folded(input_deps, derive_fn);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Calc<T> {
+    /// Build a calc like [`Calc::new`], but using a caller-supplied `should_notify` in place of
+    /// `PartialEq` to decide whether a recomputed value counts as a change. For output types that
+    /// don't implement (or are too expensive to) diff with `==` — closures, handles, large
+    /// buffers — pass `|_, _| true` to always notify, or a cheaper approximate comparison.
+    pub fn new_with<D: CalcQuery<T>>(
+        rctx: &mut ReactiveContext,
+        input_deps: D,
+        derive_fn: impl Fn(D::Query<'_>) -> T + Send + Sync + Clone + 'static,
+        should_notify: impl Fn(&T, &T) -> bool + Send + Sync + Clone + 'static,
+    ) -> Self {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        let mut calc_fn = CalcFunction::new_with(input_deps, derive_fn, should_notify);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+
+    pub fn read<'r>(&self, rctx: &'r mut ReactiveContext) -> &'r T {
+        rctx.read(*self)
+    }
+}
+
+/// Lives alongside a [`Calc`]'s [`ObservableData`] component, and holds the type-erased closure
+/// used to recompute it.
+///
+/// This component lives in the reactive world and holds the user calculation function. [`Calc`]
+/// is what users of this plugin use, which is a lightweight handle to access this mirror
+/// component.
+#[derive(Component)]
+pub(crate) struct CalcFunction {
+    function: Box<dyn DeriveFn>,
+    /// The observables this calc subscribed to on its last run, recorded via
+    /// [`ReactiveContext::read`] while it executed. These are unsubscribed before every rerun, so
+    /// a run that stops reading a branch doesn't keep a stale subscription alive.
+    deps: Vec<Box<dyn TrackedDependency>>,
+}
+
+trait DeriveFn: Send + Sync + FnMut(&mut ReactiveContext, &mut Vec<Entity>) {}
+impl<T: Send + Sync + FnMut(&mut ReactiveContext, &mut Vec<Entity>)> DeriveFn for T {}
+
+/// A type-erased upstream dependency, so a [`CalcFunction`] can hold a heterogeneous set of
+/// observables and unsubscribe from each without knowing their concrete value types.
+pub(crate) trait TrackedDependency: Send + Sync {
+    fn unsubscribe(&self, world: &mut World, observer: Entity);
+}
+
+struct Dependency<T> {
+    entity: Entity,
+    p: PhantomData<T>,
+}
+
+impl<T: Send + Sync + 'static> TrackedDependency for Dependency<T> {
+    fn unsubscribe(&self, world: &mut World, observer: Entity) {
+        if let Some(mut data) = world.get_mut::<ObservableData<T>>(self.entity) {
+            data.unsubscribe(observer);
+        }
+    }
+}
+
+/// Record that the currently-executing observer read `entity` as a `T`, so it can be
+/// unsubscribed from on the next rerun. Used by [`ReactiveContext::read`].
+pub(crate) fn track<T: Send + Sync + 'static>(entity: Entity) -> Box<dyn TrackedDependency> {
+    Box::new(Dependency {
+        entity,
+        p: PhantomData::<T>,
+    })
+}
+
+impl CalcFunction {
+    pub(crate) fn new<C: Send + Sync + PartialEq + 'static, D: CalcQuery<C> + 'static>(
+        input_deps: D,
+        derive_fn: (impl Fn(D::Query<'_>) -> C + Send + Sync + Clone + 'static),
+    ) -> Self {
+        let function = move |rctx: &mut ReactiveContext, stack: &mut Vec<Entity>| {
+            let derived = rctx
+                .current_observer()
+                .expect("CalcFunction::execute always runs with an observer pushed");
+            let computed_value = D::read_and_derive(rctx, derived, derive_fn.clone(), input_deps);
+            if let Some(computed_value) = computed_value {
+                apply(&mut rctx.world, derived, computed_value, stack);
+            }
+        };
+        Self {
+            function: Box::new(function),
+            deps: Vec::new(),
+        }
+    }
+
+    pub(crate) fn new_folded<C: Send + Sync + PartialEq + 'static, D: CalcQuery<C> + 'static>(
+        input_deps: D,
+        derive_fn: (impl Fn(Option<&C>, D::Query<'_>) -> C + Send + Sync + Clone + 'static),
+    ) -> Self {
+        let function = move |rctx: &mut ReactiveContext, stack: &mut Vec<Entity>| {
+            let derived = rctx
+                .current_observer()
+                .expect("CalcFunction::execute always runs with an observer pushed");
+            let computed_value =
+                D::read_and_derive_folded(rctx, derived, derive_fn.clone(), input_deps);
+            if let Some(computed_value) = computed_value {
+                apply(&mut rctx.world, derived, computed_value, stack);
+            }
+        };
+        Self {
+            function: Box::new(function),
+            deps: Vec::new(),
+        }
+    }
+
+    /// Like [`CalcFunction::new`], but diffs recomputed values with a caller-supplied
+    /// `should_notify` instead of requiring `C: PartialEq`. See [`Calc::new_with`].
+    pub(crate) fn new_with<C: Send + Sync + 'static, D: CalcQuery<C> + 'static>(
+        input_deps: D,
+        derive_fn: impl Fn(D::Query<'_>) -> C + Send + Sync + Clone + 'static,
+        should_notify: impl Fn(&C, &C) -> bool + Send + Sync + Clone + 'static,
+    ) -> Self {
+        let function = move |rctx: &mut ReactiveContext, stack: &mut Vec<Entity>| {
+            let derived = rctx
+                .current_observer()
+                .expect("CalcFunction::execute always runs with an observer pushed");
+            let computed_value = D::read_and_derive(rctx, derived, derive_fn.clone(), input_deps);
+            if let Some(computed_value) = computed_value {
+                apply_with(&mut rctx.world, derived, computed_value, &should_notify, stack);
+            }
+        };
+        Self {
+            function: Box::new(function),
+            deps: Vec::new(),
+        }
+    }
+
+    pub(crate) fn new_dynamic<T: Send + Sync + PartialEq + 'static>(
+        derive_fn: impl Fn(&mut ReactiveContext) -> T + Send + Sync + Clone + 'static,
+    ) -> Self {
+        let function = move |rctx: &mut ReactiveContext, stack: &mut Vec<Entity>| {
+            let derived = rctx
+                .current_observer()
+                .expect("CalcFunction::execute always runs with an observer pushed");
+            let computed_value = derive_fn(rctx);
+            apply(&mut rctx.world, derived, computed_value, stack);
+        };
+        Self {
+            function: Box::new(function),
+            deps: Vec::new(),
+        }
+    }
+
+    /// (Re)run this calc's derivation. Stale subscriptions from the previous run are torn down
+    /// first, then `derived` is pushed as the active observer for the duration of the run so
+    /// every [`ReactiveContext::read`] inside the closure gets attributed to it, and the newly
+    /// discovered dependency set replaces the old one once it returns.
+    pub(crate) fn execute(
+        &mut self,
+        rctx: &mut ReactiveContext,
+        stack: &mut Vec<Entity>,
+        derived: Entity,
+    ) {
+        self.disconnect(&mut rctx.world, derived);
+        rctx.push_observer(derived);
+        (self.function)(rctx, stack);
+        self.deps = rctx.pop_observer();
+    }
+
+    /// Unsubscribe this calc from every dependency it's currently tracking. Used both to clear
+    /// stale subscriptions before a rerun, and to tear a calc down entirely on disposal (see
+    /// [`crate::owner::Scope::dispose`]).
+    pub(crate) fn disconnect(&mut self, world: &mut World, derived: Entity) {
+        for dep in self.deps.drain(..) {
+            dep.unsubscribe(world, derived);
+        }
+    }
+}
+
+/// Stores `computed_value` in `derived`'s [`ObservableData`], diffing against the previous value
+/// and pushing any subscribers onto `stack` if it changed.
+pub(crate) fn apply<T: Send + Sync + PartialEq + 'static>(
+    world: &mut World,
+    derived: Entity,
+    computed_value: T,
+    stack: &mut Vec<Entity>,
+) {
+    apply_with(world, derived, computed_value, &|old, new| old != new, stack)
+}
+
+/// Like [`apply`], but decides whether `computed_value` is a change using `should_notify`
+/// instead of `PartialEq`, so types that can't (or shouldn't) be diffed with `==` can still
+/// participate. See [`Calc::new_with`].
+pub(crate) fn apply_with<T: Send + Sync + 'static>(
+    world: &mut World,
+    derived: Entity,
+    computed_value: T,
+    should_notify: &impl Fn(&T, &T) -> bool,
+    stack: &mut Vec<Entity>,
+) {
+    if let Some(mut data) = world.get_mut::<ObservableData<T>>(derived) {
+        if !should_notify(data.data(), &computed_value) {
+            return; // Diff the value and early exit if no change.
+        }
+        data.set_data(computed_value);
+        // Remove all subscribers from this entity. If any of these subscribers end up using
+        // this data, they will resubscribe themselves. This is the auto-unsubscribe part of
+        // the reactive implementation.
+        //
+        // We push these subscribers on the stack, so that they can be executed, just like this
+        // one was. We use a stack instead of recursion to avoid stack overflow.
+        stack.append(&mut data.drain_subscribers());
+    } else {
+        world
+            .entity_mut(derived)
+            .insert(ObservableData::new(computed_value));
+    }
+}
+
+/// Drain `stack`, executing each entity's [`CalcFunction`] in turn. Executing one can push more
+/// entities onto `stack` (its subscribers, if its value changed), so this keeps going until the
+/// whole affected subgraph has recomputed.
+pub(crate) fn drain_stack(rctx: &mut ReactiveContext, stack: &mut Vec<Entity>) {
+    while let Some(entity) = stack.pop() {
+        if let Some(mut calc_fn) = rctx.world.entity_mut(entity).take::<CalcFunction>() {
+            calc_fn.execute(rctx, stack, entity);
+            rctx.world.entity_mut(entity).insert(calc_fn);
+        }
+    }
+}
+
+/// Implemented on tuples to be used for querying.
+pub trait CalcQuery<T: Send + Sync + 'static>: Copy + Send + Sync + 'static {
+    type Query<'a>;
+    fn read_and_derive(
+        rctx: &mut ReactiveContext,
+        reader: Entity,
+        derive_fn: impl Fn(Self::Query<'_>) -> T,
+        input_deps: Self,
+    ) -> Option<T>;
+
+    /// Like [`CalcQuery::read_and_derive`], but also passes the reader's current value (before
+    /// this run overwrites it) to `derive_fn`, so it can fold the new reading into the existing
+    /// value instead of recomputing from scratch.
+    fn read_and_derive_folded(
+        rctx: &mut ReactiveContext,
+        reader: Entity,
+        derive_fn: impl Fn(Option<&T>, Self::Query<'_>) -> T,
+        input_deps: Self,
+    ) -> Option<T>;
+}
+
+macro_rules! impl_CalcQuery {
+    ($N: expr, $(($T: ident, $I: ident)),*) => {
+        impl<$($T: Observable), *, D: Send + Sync + 'static> CalcQuery<D> for ($($T,)*) {
+            type Query<'a> = ($(&'a $T::DataType,)*);
+
+            fn read_and_derive(
+                rctx: &mut ReactiveContext,
+                reader: Entity,
+                derive_fn: impl Fn(Self::Query<'_>) -> D,
+                entities: Self,
+            ) -> Option<D> {
+                let ($($I,)*) = entities;
+                let entities = [$($I.reactive_entity(),)*];
+
+                // Note this is left to unwrap intentionally. If aliased mutability happens, this is
+                // an error and should panic. If we were to early exit here, it would lead to
+                // harder-to-debug errors down the line.
+                let [$(mut $I,)*] = rctx.world.get_many_entities_mut(entities).unwrap();
+
+                $($I.get_mut::<ObservableData<$T::DataType>>()?.subscribe(reader);)*
+
+                // Record these as reverse edges too, same as `ReactiveContext::read`, so disposal
+                // can unsubscribe this calc from each of them later.
+                $(
+                    if let Some(deps) = rctx.tracked_deps.last_mut() {
+                        deps.push(track::<$T::DataType>($I.id()));
+                    }
+                )*
+
+                Some(derive_fn((
+                    $($I.get::<ObservableData<$T::DataType>>()?.data(),)*
+                )))
+            }
+
+            fn read_and_derive_folded(
+                rctx: &mut ReactiveContext,
+                reader: Entity,
+                derive_fn: impl Fn(Option<&D>, Self::Query<'_>) -> D,
+                entities: Self,
+            ) -> Option<D> {
+                let ($($I,)*) = entities;
+                // Fetch the reader alongside its dependencies so we can read its previous value
+                // without fighting the borrow checker over two separate `&mut World` accesses.
+                let entities = [$($I.reactive_entity(),)* reader];
+
+                let [$(mut $I,)* this] = rctx.world.get_many_entities_mut(entities).unwrap();
+
+                $($I.get_mut::<ObservableData<$T::DataType>>()?.subscribe(reader);)*
+
+                $(
+                    if let Some(deps) = rctx.tracked_deps.last_mut() {
+                        deps.push(track::<$T::DataType>($I.id()));
+                    }
+                )*
+
+                let prev = this.get::<ObservableData<D>>().map(|data| data.data());
+                Some(derive_fn(prev, (
+                    $($I.get::<ObservableData<$T::DataType>>()?.data(),)*
+                )))
+            }
+        }
+    }
+}
+
+all_tuples_with_size!(impl_CalcQuery, 1, 32, T, s);
@@ -0,0 +1,49 @@
+//! Bridges from the reactive graph back out to the rest of bevy.
+//!
+//! Everything in [`crate::calculation`] and [`crate::signal`] describes pure values living
+//! *inside* the graph. An [`Effect`] is the exit point: a terminal node that runs a closure for
+//! its side effect rather than its return value, subscribing to its inputs the same way a
+//! [`crate::calculation::Calc`] does, but never itself having subscribers.
+
+use crate::{
+    calculation::{CalcFunction, CalcQuery},
+    ReactiveContext,
+};
+
+/// A terminal reactive side effect: it runs once immediately when created, and re-runs whenever
+/// any of its dependencies change. Unlike [`crate::calculation::Calc`], it holds no readable
+/// value and can't be anyone's dependency itself — it's meant for logging, writing to a real bevy
+/// component, or otherwise reaching outside the reactive graph.
+///
+/// This handle carries no data of its own; an effect's entity is torn down the same way any other
+/// observable's is, through [`crate::owner::Scope`].
+pub struct Effect;
+
+impl Effect {
+    fn new<D: CalcQuery<()>>(
+        rctx: &mut ReactiveContext,
+        deps: D,
+        effect_fn: impl Fn(D::Query<'_>) + Send + Sync + Clone + 'static,
+    ) -> Self {
+        let entity = rctx.world.spawn_empty().id();
+        rctx.record_entity(entity);
+        // An effect is a `Calc<()>` under the hood: there's no value to diff, just a closure that
+        // needs to run every time it's scheduled, and `()` is trivially `PartialEq` so the normal
+        // diffing short-circuit never blocks it from running.
+        let mut calc_fn = CalcFunction::new(deps, effect_fn);
+        calc_fn.execute(rctx, &mut Vec::new(), entity);
+        rctx.world.entity_mut(entity).insert(calc_fn);
+        Self
+    }
+}
+
+impl ReactiveContext {
+    /// Run `effect_fn` immediately, and again every time one of `deps` changes. See [`Effect`].
+    pub fn effect<D: CalcQuery<()>>(
+        &mut self,
+        deps: D,
+        effect_fn: impl Fn(D::Query<'_>) + Send + Sync + Clone + 'static,
+    ) -> Effect {
+        Effect::new(self, deps, effect_fn)
+    }
+}
@@ -20,14 +20,23 @@ use observable::{Observable, ObservableData};
 use prelude::Calc;
 use signal::Signal;
 
+pub mod async_calc;
 pub mod calculation;
 pub mod callback;
 pub mod observable;
+pub mod owner;
 pub mod signal;
+pub mod trigger;
 
 pub mod prelude {
     pub use crate::{
-        calculation::Calc, signal::Signal, ReactiveContext, ReactiveExtensionsPlugin, Reactor,
+        async_calc::{AsyncCalc, AsyncState},
+        calculation::Calc,
+        callback::Effect,
+        owner::Scope,
+        signal::Signal,
+        trigger::Trigger,
+        ReactiveContext, ReactiveExtensionsPlugin, Reactor,
     };
 }
 
@@ -35,9 +44,16 @@ pub struct ReactiveExtensionsPlugin;
 impl bevy_app::Plugin for ReactiveExtensionsPlugin {
     fn build(&self, app: &mut bevy_app::App) {
         app.init_resource::<ReactiveContext>();
+        app.add_systems(bevy_app::Update, poll_async_calcs);
     }
 }
 
+/// Drives [`AsyncCalc`](async_calc::AsyncCalc) completion: polls every in-flight task once a
+/// frame and feeds resolved values back into the graph.
+fn poll_async_calcs(mut rctx: ResMut<ReactiveContext>) {
+    rctx.poll_pending_async();
+}
+
 /// A system param to make accessing the [`ReactiveContext`] less verbose.
 #[derive(SystemParam)]
 pub struct Reactor<'w>(ResMut<'w, ReactiveContext>);
@@ -58,22 +74,42 @@ impl<'w> DerefMut for Reactor<'w> {
 /// typed data in a type erased container.
 #[derive(Default, Resource)]
 pub struct ReactiveContext {
-    world: World,
+    pub(crate) world: World,
+    /// Stack of entities currently (re)computing; the top is the active "observer" whose
+    /// dependencies are being discovered by [`ReactiveContext::read`].
+    observer_stack: Vec<Entity>,
+    /// Dependencies discovered so far for the corresponding entry in `observer_stack`.
+    pub(crate) tracked_deps: Vec<Vec<Box<dyn calculation::TrackedDependency>>>,
+    /// Stack of in-progress [`owner::Scope`]s; the top records every observable entity spawned
+    /// while it's active, so the scope can later dispose of all of them together.
+    scope_stack: Vec<Vec<Entity>>,
+    /// In-flight tasks spawned by [`async_calc::AsyncCalc`]s, polled once a frame by
+    /// [`ReactiveExtensionsPlugin`].
+    pub(crate) pending_async: Vec<Box<dyn async_calc::ErasedPendingTask>>,
 }
 
 impl ReactiveContext {
     /// Returns a reference to the current value of the provided observable. The observable is any
-    /// reactive handle that has a value, like a [`Signal`] or a [`Derived`].
-    pub fn read<T: Send + Sync + PartialEq + 'static, O: Observable<DataType = T>>(
+    /// reactive handle that has a value, like a [`Signal`] or a [`Calc`].
+    ///
+    /// If this is called while a [`Calc`] is (re)computing, the calc is recorded as a subscriber
+    /// of `observable`, and `observable` is recorded as one of the calc's dependencies — so only
+    /// the observables a derivation *actually* reads on a given run end up subscribed to it. See
+    /// [`Calc::new_dynamic`].
+    pub fn read<T: Send + Sync + 'static, O: Observable<DataType = T>>(
         &mut self,
         observable: O,
     ) -> &T {
-        // get the obs data from the world
-        // add the reader to the obs data's subs
-        self.world
-            .get::<ObservableData<T>>(observable.reactive_entity())
-            .unwrap()
-            .data()
+        let entity = observable.reactive_entity();
+        if let Some(observer) = self.current_observer() {
+            if let Some(mut data) = self.world.get_mut::<ObservableData<T>>(entity) {
+                data.subscribe(observer);
+            }
+            if let Some(deps) = self.tracked_deps.last_mut() {
+                deps.push(calculation::track::<T>(entity));
+            }
+        }
+        self.world.get::<ObservableData<T>>(entity).unwrap().data()
     }
 
     /// Send a signal, and run the reaction graph to completion.
@@ -86,7 +122,16 @@ impl ReactiveContext {
         signal: Signal<T>,
         value: T,
     ) {
-        ObservableData::send_signal(&mut self.world, signal.reactive_entity(), value)
+        let entity = signal.reactive_entity();
+        let mut stack = Vec::new();
+        if let Some(mut data) = self.world.get_mut::<ObservableData<T>>(entity) {
+            if *data.data() == value {
+                return; // Diff the value and early exit if no change.
+            }
+            data.set_data(value);
+            stack.append(&mut data.drain_subscribers());
+        }
+        calculation::drain_stack(self, &mut stack);
     }
 
     pub fn signal<T: Send + Sync + PartialEq + 'static>(&mut self, initial_value: T) -> Signal<T> {
@@ -100,6 +145,73 @@ impl ReactiveContext {
     ) -> Calc<T> {
         Calc::new(self, calculation_query, derive_fn)
     }
+
+    /// Like [`ReactiveContext::calc`], but the derivation's inputs are discovered dynamically by
+    /// recording whatever it reads through `cx.read(..)` each time it runs, instead of being
+    /// declared up front as a tuple. See [`Calc::new_dynamic`].
+    pub fn calc_dynamic<T: Send + Sync + PartialEq + 'static>(
+        &mut self,
+        derive_fn: impl Fn(&mut ReactiveContext) -> T + Send + Sync + Clone + 'static,
+    ) -> Calc<T> {
+        Calc::new_dynamic(self, derive_fn)
+    }
+
+    /// Like [`ReactiveContext::calc`], but `derive_fn` also receives its own previous value
+    /// (`None` on the first run), so it can fold the new reading in incrementally instead of
+    /// recomputing from scratch. See [`Calc::new_folded`].
+    pub fn calc_folded<T: Send + Sync + PartialEq + 'static, C: CalcQuery<T> + 'static>(
+        &mut self,
+        calculation_query: C,
+        derive_fn: (impl Fn(Option<&T>, C::Query<'_>) -> T + Send + Sync + Clone + 'static),
+    ) -> Calc<T> {
+        Calc::new_folded(self, calculation_query, derive_fn)
+    }
+
+    /// Like [`ReactiveContext::calc`], but for output types that don't implement `PartialEq` (or
+    /// are too expensive to diff with `==`): `should_notify` decides whether a recomputed value
+    /// counts as a change, in place of the usual `==` check. See [`Calc::new_with`].
+    pub fn calc_with<T: Send + Sync + 'static, C: CalcQuery<T> + 'static>(
+        &mut self,
+        calculation_query: C,
+        derive_fn: impl Fn(C::Query<'_>) -> T + Send + Sync + Clone + 'static,
+        should_notify: impl Fn(&T, &T) -> bool + Send + Sync + Clone + 'static,
+    ) -> Calc<T> {
+        Calc::new_with(self, calculation_query, derive_fn, should_notify)
+    }
+
+    pub(crate) fn current_observer(&self) -> Option<Entity> {
+        self.observer_stack.last().copied()
+    }
+
+    pub(crate) fn push_observer(&mut self, observer: Entity) {
+        self.observer_stack.push(observer);
+        self.tracked_deps.push(Vec::new());
+    }
+
+    pub(crate) fn pop_observer(&mut self) -> Vec<Box<dyn calculation::TrackedDependency>> {
+        self.observer_stack.pop();
+        self.tracked_deps.pop().unwrap_or_default()
+    }
+
+    /// Run `f`, recording every observable entity it creates (signals, calcs, effects), and
+    /// return an [`owner::Scope`] handle that can later despawn all of them together via
+    /// [`owner::Scope::dispose`].
+    ///
+    /// This gives bounded-lifetime reactive graphs — e.g. for a UI screen or game state — that
+    /// can be spun up and torn down without growing the world forever.
+    pub fn scope(&mut self, f: impl FnOnce(&mut ReactiveContext)) -> owner::Scope {
+        self.scope_stack.push(Vec::new());
+        f(self);
+        owner::Scope::new(self.scope_stack.pop().unwrap_or_default())
+    }
+
+    /// Record `entity` against the innermost in-progress [`owner::Scope`], if any. Called by
+    /// every constructor that spawns a new observable entity (signals, calcs, effects).
+    pub(crate) fn record_entity(&mut self, entity: Entity) {
+        if let Some(scope) = self.scope_stack.last_mut() {
+            scope.push(entity);
+        }
+    }
 }
 
 mod test {
@@ -188,6 +300,184 @@ mod test {
         assert_eq!(reactor.read(baz), &Baz(2.0));
     }
 
+    #[test]
+    fn dynamic_dependency_tracking() {
+        let mut reactor = crate::ReactiveContext::default();
+
+        let flag = reactor.signal(false);
+        let a = reactor.signal(1);
+        let b = reactor.signal(2);
+
+        // Only reads `b` on the branch it actually takes, so it should not be subscribed to `a`.
+        let selected =
+            reactor.calc_dynamic(move |cx| if *cx.read(flag) { *cx.read(a) } else { *cx.read(b) });
+        assert_eq!(*reactor.read(selected), 2);
+
+        // `a` isn't a dependency right now, so changing it must not trigger a recompute.
+        reactor.send_signal(a, 100);
+        assert_eq!(*reactor.read(selected), 2);
+
+        // Flipping `flag` switches the branch, which re-subscribes to `a` and drops `b`.
+        reactor.send_signal(flag, true);
+        assert_eq!(*reactor.read(selected), 100);
+
+        reactor.send_signal(b, 999);
+        assert_eq!(*reactor.read(selected), 100);
+
+        reactor.send_signal(a, 42);
+        assert_eq!(*reactor.read(selected), 42);
+    }
+
+    #[test]
+    fn effect_reruns_on_dependency_change() {
+        use std::sync::atomic::{AtomicI32, Ordering};
+        use std::sync::Arc;
+
+        let mut reactor = crate::ReactiveContext::default();
+
+        let count = reactor.signal(0);
+        let runs = Arc::new(AtomicI32::new(0));
+        let seen = Arc::new(AtomicI32::new(-1));
+
+        let runs_inner = runs.clone();
+        let seen_inner = seen.clone();
+        reactor.effect((count,), move |(count,)| {
+            runs_inner.fetch_add(1, Ordering::SeqCst);
+            seen_inner.store(*count, Ordering::SeqCst);
+        });
+        assert_eq!(runs.load(Ordering::SeqCst), 1); // Runs once immediately.
+        assert_eq!(seen.load(Ordering::SeqCst), 0);
+
+        reactor.send_signal(count, 1);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+
+        reactor.send_signal(count, 1); // Diffing prevents a redundant recompute.
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn folded_calc_accumulates_incrementally() {
+        let mut reactor = crate::ReactiveContext::default();
+
+        let next = reactor.signal(1);
+        let running_sum =
+            reactor.calc_folded((next,), |prev: Option<&i32>, (next,)| prev.unwrap_or(&0) + next);
+        assert_eq!(*reactor.read(running_sum), 1);
+
+        reactor.send_signal(next, 2);
+        assert_eq!(*reactor.read(running_sum), 3);
+
+        reactor.send_signal(next, 5);
+        assert_eq!(*reactor.read(running_sum), 8);
+    }
+
+    #[test]
+    fn scope_disposes_without_leaving_stale_subscriptions() {
+        let mut reactor = crate::ReactiveContext::default();
+
+        let source = reactor.signal(1);
+        let scope = reactor.scope(|cx| {
+            let _doubled = cx.calc((source,), |(n,): (&i32,)| n * 2);
+        });
+
+        // Disposing tears down the calc created inside the scope, unsubscribing it from `source`
+        // along the way.
+        scope.dispose(&mut reactor);
+
+        // If disposal had failed to unsubscribe the calc from `source`, this would try to
+        // recompute a despawned entity and panic.
+        reactor.send_signal(source, 2);
+    }
+
+    #[test]
+    fn async_calc_resolves_and_feeds_back_into_the_graph() {
+        bevy_tasks::AsyncComputeTaskPool::get_or_init(bevy_tasks::TaskPool::new);
+
+        let mut reactor = crate::ReactiveContext::default();
+
+        let n = reactor.signal(1);
+        let doubled = reactor.calc_async(move |cx| {
+            let n = *cx.read(n);
+            async move { n * 2 }
+        });
+        assert_eq!(*reactor.read(doubled), crate::async_calc::AsyncState::Loading);
+
+        // Drain the task pool until the spawned future actually completes.
+        while reactor.read(doubled) == &crate::async_calc::AsyncState::Loading {
+            reactor.poll_pending_async();
+        }
+        assert_eq!(*reactor.read(doubled), crate::async_calc::AsyncState::Ready(2));
+
+        // Changing a dependency re-dispatches the future and bumps the generation even before it
+        // resolves, so a stale in-flight task from before this change can't clobber the result.
+        let version_before = doubled.version(&reactor);
+        reactor.send_signal(n, 10);
+        assert_eq!(*reactor.read(doubled), crate::async_calc::AsyncState::Loading);
+        assert!(doubled.version(&reactor) > version_before);
+
+        while reactor.read(doubled) == &crate::async_calc::AsyncState::Loading {
+            reactor.poll_pending_async();
+        }
+        assert_eq!(*reactor.read(doubled), crate::async_calc::AsyncState::Ready(20));
+    }
+
+    #[test]
+    fn trigger_forces_recompute_regardless_of_value() {
+        use std::sync::atomic::{AtomicI32, Ordering};
+        use std::sync::Arc;
+
+        let mut reactor = crate::ReactiveContext::default();
+
+        let trigger = reactor.trigger();
+        let runs = Arc::new(AtomicI32::new(0));
+
+        let runs_inner = runs.clone();
+        let count = reactor.calc_dynamic(move |cx| {
+            trigger.track(cx);
+            runs_inner.fetch_add(1, Ordering::SeqCst)
+        });
+        assert_eq!(*reactor.read(count), 0);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Firing the trigger reruns subscribers even though nothing it "carries" has changed —
+        // there's nothing to diff.
+        reactor.notify(trigger);
+        assert_eq!(*reactor.read(count), 1);
+        reactor.notify(trigger);
+        assert_eq!(*reactor.read(count), 2);
+    }
+
+    #[test]
+    fn calc_with_supports_non_partial_eq_output() {
+        use std::sync::atomic::{AtomicI32, Ordering};
+        use std::sync::Arc;
+
+        // Deliberately has no `PartialEq`, so it could never be used with `Calc::new`.
+        struct Opaque(i32);
+
+        let mut reactor = crate::ReactiveContext::default();
+
+        let source = reactor.signal(1);
+        let opaque = reactor.calc_with(
+            (source,),
+            |(n,): (&i32,)| Opaque(*n),
+            |_: &Opaque, _: &Opaque| true, // Always notify; there's nothing to diff.
+        );
+        assert_eq!(opaque.read(&mut reactor).0, 1);
+
+        let runs = Arc::new(AtomicI32::new(0));
+        let runs_inner = runs.clone();
+        reactor.effect((opaque,), move |(_,): (&Opaque,)| {
+            runs_inner.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        reactor.send_signal(source, 2);
+        assert_eq!(opaque.read(&mut reactor).0, 2);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn calculate_pi() {
         let mut reactor = crate::ReactiveContext::default();
@@ -0,0 +1,53 @@
+use bevy_ecs::prelude::*;
+
+/// A lightweight, `Copy` handle to a reactive value living inside a [`crate::ReactiveContext`]'s
+/// inner world. Implemented by [`crate::signal::Signal`] and [`crate::calculation::Calc`]; the
+/// handle itself carries no data, the value lives in the paired [`ObservableData`] component.
+pub trait Observable: Copy + Send + Sync + 'static {
+    type DataType: Send + Sync + 'static;
+    fn reactive_entity(&self) -> Entity;
+}
+
+/// Component holding the actual value behind an [`Observable`] handle, along with the set of
+/// entities that currently depend on it.
+///
+/// Subscribers are rebuilt from scratch every time a dependent recomputes (see
+/// [`crate::ReactiveContext::read`]), so this only ever reflects the *current* set of readers,
+/// not every reader that has ever existed.
+#[derive(Component)]
+pub struct ObservableData<T> {
+    data: T,
+    pub(crate) subscribers: Vec<Entity>,
+}
+
+impl<T> ObservableData<T> {
+    pub(crate) fn new(data: T) -> Self {
+        Self {
+            data,
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// The current value of this observable.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    pub(crate) fn set_data(&mut self, data: T) {
+        self.data = data;
+    }
+
+    pub(crate) fn subscribe(&mut self, subscriber: Entity) {
+        if !self.subscribers.contains(&subscriber) {
+            self.subscribers.push(subscriber);
+        }
+    }
+
+    pub(crate) fn unsubscribe(&mut self, subscriber: Entity) {
+        self.subscribers.retain(|entity| *entity != subscriber);
+    }
+
+    pub(crate) fn drain_subscribers(&mut self) -> Vec<Entity> {
+        std::mem::take(&mut self.subscribers)
+    }
+}
@@ -0,0 +1,35 @@
+//! Bounded-lifetime reactive graphs.
+//!
+//! Every [`crate::signal::Signal`] and [`crate::calculation::Calc`] spawns an entity in the
+//! [`ReactiveContext`]'s inner world that otherwise lives forever. [`Scope`] lets a subgraph be
+//! torn down together instead — useful for reactive state that's scoped to a UI screen or a game
+//! state and shouldn't outlive it.
+
+use bevy_ecs::prelude::*;
+
+use crate::{calculation::CalcFunction, ReactiveContext};
+
+/// A handle to a group of observable entities created together via [`ReactiveContext::scope`].
+/// Dropping this handle does nothing by itself — call [`Scope::dispose`] to actually despawn the
+/// entities it recorded.
+pub struct Scope {
+    entities: Vec<Entity>,
+}
+
+impl Scope {
+    pub(crate) fn new(entities: Vec<Entity>) -> Self {
+        Self { entities }
+    }
+
+    /// Despawn every entity this scope recorded. A [`crate::calculation::Calc`] or
+    /// [`crate::callback::Effect`] is first unsubscribed from each of its upstream dependencies,
+    /// so their subscriber lists never end up pointing at a despawned entity.
+    pub fn dispose(self, rctx: &mut ReactiveContext) {
+        for entity in self.entities {
+            if let Some(mut calc_fn) = rctx.world.entity_mut(entity).take::<CalcFunction>() {
+                calc_fn.disconnect(&mut rctx.world, entity);
+            }
+            rctx.world.despawn(entity);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::{
+    observable::{Observable, ObservableData},
+    ReactiveContext,
+};
+
+/// A reactive value that is set directly, as opposed to a [`crate::calculation::Calc`], whose
+/// value is derived from other observables. Like [`crate::calculation::Calc`], this is a
+/// lightweight handle; the value itself lives in the [`ReactiveContext`].
+#[derive(Debug, Component)]
+pub struct Signal<T: Send + Sync + 'static> {
+    pub(crate) reactor_entity: Entity,
+    pub(crate) p: PhantomData<T>,
+}
+
+impl<T: Send + Sync> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync> Copy for Signal<T> {}
+
+impl<T: Send + Sync + PartialEq + 'static> Observable for Signal<T> {
+    type DataType = T;
+    fn reactive_entity(&self) -> Entity {
+        self.reactor_entity
+    }
+}
+
+impl<T: Send + Sync + PartialEq + 'static> Signal<T> {
+    pub(crate) fn new(rctx: &mut ReactiveContext, initial_value: T) -> Self {
+        let entity = rctx.world.spawn(ObservableData::new(initial_value)).id();
+        rctx.record_entity(entity);
+        Self {
+            reactor_entity: entity,
+            p: PhantomData,
+        }
+    }
+
+    /// Set this signal's value, and run the reaction graph to completion.
+    pub fn send(&self, rctx: &mut ReactiveContext, value: T) {
+        rctx.send_signal(*self, value);
+    }
+
+    /// Read this signal's current value. If called while a [`crate::calculation::Calc`] is
+    /// (re)computing, this records that calc as a subscriber, same as [`ReactiveContext::read`].
+    pub fn read<'r>(&self, rctx: &'r mut ReactiveContext) -> &'r T {
+        rctx.read(*self)
+    }
+}
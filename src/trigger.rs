@@ -0,0 +1,117 @@
+//! A value-less observable: fires to tell subscribers "something changed," without asserting
+//! what changed or to what.
+//!
+//! Every other observable in this crate carries a value and diffs it before deciding whether to
+//! notify subscribers — with `PartialEq` (see [`crate::calculation::apply`]), or with a
+//! caller-supplied `should_notify` for a [`crate::calculation::Calc::new_with`] calc. A
+//! [`Trigger`] has no value to diff, so there's nothing to short-circuit: every
+//! [`ReactiveContext::notify`] unconditionally reruns its subscribers. A derivation subscribes to
+//! a trigger by calling [`Trigger::track`], which needs the [`ReactiveContext`]; only
+//! dynamically-tracked derivations (see [`crate::calculation::Calc::new_dynamic`]) are handed
+//! one, so a calc built from a fixed input tuple can't depend on a trigger.
+
+use bevy_ecs::prelude::*;
+
+use crate::{calculation::TrackedDependency, ReactiveContext};
+
+/// Component paired with a [`Trigger`] entity: just the subscriber list, no value.
+#[derive(Component, Default)]
+pub(crate) struct TriggerData {
+    subscribers: Vec<Entity>,
+}
+
+impl TriggerData {
+    fn subscribe(&mut self, subscriber: Entity) {
+        if !self.subscribers.contains(&subscriber) {
+            self.subscribers.push(subscriber);
+        }
+    }
+
+    fn unsubscribe(&mut self, subscriber: Entity) {
+        self.subscribers.retain(|entity| *entity != subscriber);
+    }
+
+    fn drain_subscribers(&mut self) -> Vec<Entity> {
+        std::mem::take(&mut self.subscribers)
+    }
+}
+
+/// A reactive signal that carries no data. Firing it via [`ReactiveContext::notify`] tells every
+/// subscriber to recompute, the same way changing a [`crate::signal::Signal`]'s value would —
+/// just without a value to inspect.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct Trigger {
+    pub(crate) reactor_entity: Entity,
+}
+
+impl Trigger {
+    pub(crate) fn new(rctx: &mut ReactiveContext) -> Self {
+        let entity = rctx.world.spawn(TriggerData::default()).id();
+        rctx.record_entity(entity);
+        Self {
+            reactor_entity: entity,
+        }
+    }
+
+    /// Subscribe the currently (re)computing [`crate::calculation::Calc`] to this trigger, same
+    /// as [`ReactiveContext::read`] does for value-bearing observables. Call from inside a
+    /// dynamically-tracked derivation (see [`crate::calculation::Calc::new_dynamic`]) to make it
+    /// rerun whenever this trigger fires.
+    pub fn track(&self, rctx: &mut ReactiveContext) {
+        rctx.track_trigger(*self);
+    }
+
+    /// Fire this trigger, unconditionally rerunning every subscriber. See
+    /// [`ReactiveContext::notify`].
+    pub fn notify(&self, rctx: &mut ReactiveContext) {
+        rctx.notify(*self);
+    }
+}
+
+struct TriggerDependency {
+    entity: Entity,
+}
+
+impl TrackedDependency for TriggerDependency {
+    fn unsubscribe(&self, world: &mut World, observer: Entity) {
+        if let Some(mut data) = world.get_mut::<TriggerData>(self.entity) {
+            data.unsubscribe(observer);
+        }
+    }
+}
+
+pub(crate) fn track(entity: Entity) -> Box<dyn TrackedDependency> {
+    Box::new(TriggerDependency { entity })
+}
+
+impl ReactiveContext {
+    /// Create a new [`Trigger`].
+    pub fn trigger(&mut self) -> Trigger {
+        Trigger::new(self)
+    }
+
+    /// Subscribe the current observer to `trigger`, if one is (re)computing. See
+    /// [`Trigger::track`].
+    pub(crate) fn track_trigger(&mut self, trigger: Trigger) {
+        let entity = trigger.reactor_entity;
+        if let Some(observer) = self.current_observer() {
+            if let Some(mut data) = self.world.get_mut::<TriggerData>(entity) {
+                data.subscribe(observer);
+            }
+            if let Some(deps) = self.tracked_deps.last_mut() {
+                deps.push(track(entity));
+            }
+        }
+    }
+
+    /// Fire `trigger`, unconditionally rerunning its subscribers and running the reaction graph
+    /// to completion. See [`Trigger::notify`].
+    pub fn notify(&mut self, trigger: Trigger) {
+        let entity = trigger.reactor_entity;
+        let mut stack = Vec::new();
+        if let Some(mut data) = self.world.get_mut::<TriggerData>(entity) {
+            stack.append(&mut data.drain_subscribers());
+        }
+        crate::calculation::drain_stack(self, &mut stack);
+    }
+}